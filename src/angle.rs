@@ -0,0 +1,110 @@
+//! Typed angle units, so a bare scalar can never be mistaken for degrees where radians
+//! (or vice versa) are expected.
+
+use std::ops;
+
+use super::scalar::Scalar;
+
+/// An angle in radians.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Rad<T: Scalar>(pub T);
+
+/// An angle in degrees.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Deg<T: Scalar>(pub T);
+
+impl<T: Scalar> Rad<T> {
+    /// The sine of the angle.
+    pub fn sin(self) -> T {
+        self.0.sin()
+    }
+
+    /// The cosine of the angle.
+    pub fn cos(self) -> T {
+        self.0.cos()
+    }
+
+    /// The tangent of the angle.
+    pub fn tan(self) -> T {
+        self.0.tan()
+    }
+}
+
+impl<T: Scalar> Deg<T> {
+    /// The sine of the angle.
+    pub fn sin(self) -> T {
+        Rad::from(self).sin()
+    }
+
+    /// The cosine of the angle.
+    pub fn cos(self) -> T {
+        Rad::from(self).cos()
+    }
+
+    /// The tangent of the angle.
+    pub fn tan(self) -> T {
+        Rad::from(self).tan()
+    }
+}
+
+impl<T: Scalar> From<Deg<T>> for Rad<T> {
+    /// `rad = deg * π / 180`
+    fn from(deg: Deg<T>) -> Rad<T> {
+        Rad(deg.0 * T::pi() / T::from_f64(180.0))
+    }
+}
+
+impl<T: Scalar> From<Rad<T>> for Deg<T> {
+    /// `deg = rad * 180 / π`
+    fn from(rad: Rad<T>) -> Deg<T> {
+        Deg(rad.0 * T::from_f64(180.0) / T::pi())
+    }
+}
+
+impl<T: Scalar> ops::Add for Rad<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Rad(self.0 + other.0)
+    }
+}
+
+impl<T: Scalar> ops::Sub for Rad<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Rad(self.0 - other.0)
+    }
+}
+
+impl<T: Scalar> ops::Mul<T> for Rad<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Rad(self.0 * rhs)
+    }
+}
+
+impl<T: Scalar> ops::Add for Deg<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Deg(self.0 + other.0)
+    }
+}
+
+impl<T: Scalar> ops::Sub for Deg<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Deg(self.0 - other.0)
+    }
+}
+
+impl<T: Scalar> ops::Mul<T> for Deg<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Deg(self.0 * rhs)
+    }
+}
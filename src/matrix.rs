@@ -0,0 +1,222 @@
+//! Column-major matrix types for building transforms on top of [`Vector3`]/[`Vector4`].
+//!
+//! `Matrix3` covers pure rotations (`from_axis_angle`, `look_at`); `Matrix4` adds
+//! translation and scale (`from_translation`, `from_scale`). A rotation built as a
+//! `Matrix3` embeds into a `Matrix4` via `From`, so a full T·R·S transform is
+//! `Matrix4::from_translation(t) * Matrix4::from(rotation) * Matrix4::from_scale(s)`.
+
+use std::ops;
+
+use super::prelude::{Rad, Scalar, Vector, Vector3, Vector4};
+
+/// A 3x3 column-major matrix, most commonly used to represent a pure rotation.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Matrix3<T: Scalar = f64> {
+    pub cols: [Vector3<T>; 3],
+}
+
+impl<T: Scalar> Matrix3<T> {
+
+    /// Creates a new `Matrix3` from its three column vectors.
+    pub fn from_cols(c0: Vector3<T>, c1: Vector3<T>, c2: Vector3<T>) -> Self {
+        Matrix3 { cols: [c0, c1, c2] }
+    }
+
+    /// The multiplicative identity matrix.
+    pub fn identity() -> Self {
+        Matrix3::from_cols(
+            Vector3::right(),
+            Vector3::up(),
+            Vector3::forward(),
+        )
+    }
+
+    /// Builds a rotation matrix that rotates by `angle` around `axis`, using Rodrigues'
+    /// rotation formula.
+    ///
+    /// `axis` is expected to be normalized. `angle` accepts anything convertible to
+    /// [`Rad`], so callers can pass `Deg(90.0)` directly without converting by hand.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let rot = Matrix3::from_axis_angle(Vector3::up(), Deg(90.0));
+    /// let rotated = rot * Vector3::forward();
+    ///
+    /// assert!((rotated - Vector3::right()).magnitude() < 1e-10);
+    /// ```
+    pub fn from_axis_angle(axis: Vector3<T>, angle: impl Into<Rad<T>>) -> Self {
+        let Vector3 { x, y, z } = axis;
+        let angle = angle.into();
+        let s = angle.sin();
+        let c = angle.cos();
+        let t = T::one() - c;
+
+        Matrix3::from_cols(
+            Vector3::new(t * x * x + c, t * x * y + s * z, t * x * z - s * y),
+            Vector3::new(t * x * y - s * z, t * y * y + c, t * y * z + s * x),
+            Vector3::new(t * x * z + s * y, t * y * z - s * x, t * z * z + c),
+        )
+    }
+
+    /// Builds a rotation matrix whose rows are the orthonormal basis `side`, `up`, `dir`
+    /// derived from `dir` and `up`, so it maps `dir` onto the z-axis
+    /// ([`Vector3::forward`]) and `up` (made orthogonal to `dir`) onto the y-axis. Its
+    /// transpose is the rotation that turns `forward` into `dir`.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let rot = Matrix3::look_at(Vector3::<f64>::forward(), Vector3::up());
+    ///
+    /// assert_eq!(rot, Matrix3::identity());
+    /// ```
+    pub fn look_at(dir: Vector3<T>, up: Vector3<T>) -> Self {
+        let dir = dir.normalized();
+        let side = up.cross(dir).normalized();
+        let up = dir.cross(side).normalized();
+
+        // Transpose of the columns [side, up, dir] so that rows [side; up; dir]
+        // become the rotation's basis.
+        Matrix3::from_cols(
+            Vector3::new(side.x, up.x, dir.x),
+            Vector3::new(side.y, up.y, dir.y),
+            Vector3::new(side.z, up.z, dir.z),
+        )
+    }
+}
+
+impl<T: Scalar> ops::Mul<Vector3<T>> for Matrix3<T> {
+    type Output = Vector3<T>;
+
+    /// Transforms `rhs` by the matrix.
+    fn mul(self, rhs: Vector3<T>) -> Vector3<T> {
+        self.cols[0].scale_by(rhs.x) + self.cols[1].scale_by(rhs.y) + self.cols[2].scale_by(rhs.z)
+    }
+}
+
+impl<T: Scalar> ops::Mul for Matrix3<T> {
+    type Output = Self;
+
+    /// Composes two matrices, applying `rhs` first and then `self`.
+    fn mul(self, rhs: Self) -> Self {
+        Matrix3::from_cols(self * rhs.cols[0], self * rhs.cols[1], self * rhs.cols[2])
+    }
+}
+
+/// A 4x4 column-major matrix, used for full affine transforms (translation, rotation, scale).
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Matrix4<T: Scalar = f64> {
+    pub cols: [Vector4<T>; 4],
+}
+
+impl<T: Scalar> Matrix4<T> {
+
+    /// Creates a new `Matrix4` from its four column vectors.
+    pub fn from_cols(c0: Vector4<T>, c1: Vector4<T>, c2: Vector4<T>, c3: Vector4<T>) -> Self {
+        Matrix4 { cols: [c0, c1, c2, c3] }
+    }
+
+    /// The multiplicative identity matrix.
+    pub fn identity() -> Self {
+        Matrix4::from_cols(
+            Vector4::new(T::one(), T::zero(), T::zero(), T::zero()),
+            Vector4::new(T::zero(), T::one(), T::zero(), T::zero()),
+            Vector4::new(T::zero(), T::zero(), T::one(), T::zero()),
+            Vector4::new(T::zero(), T::zero(), T::zero(), T::one()),
+        )
+    }
+
+    /// Builds a matrix that translates points by `t`.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let m = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0));
+    /// let p = m * Vector4::new(0.0, 0.0, 0.0, 1.0);
+    ///
+    /// assert_eq!(p, Vector4::new(1.0, 2.0, 3.0, 1.0));
+    /// ```
+    pub fn from_translation(t: Vector3<T>) -> Self {
+        Matrix4::from_cols(
+            Vector4::new(T::one(), T::zero(), T::zero(), T::zero()),
+            Vector4::new(T::zero(), T::one(), T::zero(), T::zero()),
+            Vector4::new(T::zero(), T::zero(), T::one(), T::zero()),
+            Vector4::new(t.x, t.y, t.z, T::one()),
+        )
+    }
+
+    /// Builds a matrix that scales points by `s` along each axis.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let m = Matrix4::from_scale(Vector3::new(2.0, 3.0, 4.0));
+    /// let p = m * Vector4::new(1.0, 1.0, 1.0, 1.0);
+    ///
+    /// assert_eq!(p, Vector4::new(2.0, 3.0, 4.0, 1.0));
+    /// ```
+    pub fn from_scale(s: Vector3<T>) -> Self {
+        Matrix4::from_cols(
+            Vector4::new(s.x, T::zero(), T::zero(), T::zero()),
+            Vector4::new(T::zero(), s.y, T::zero(), T::zero()),
+            Vector4::new(T::zero(), T::zero(), s.z, T::zero()),
+            Vector4::new(T::zero(), T::zero(), T::zero(), T::one()),
+        )
+    }
+}
+
+impl<T: Scalar> ops::Mul<Vector4<T>> for Matrix4<T> {
+    type Output = Vector4<T>;
+
+    /// Transforms `rhs` by the matrix.
+    fn mul(self, rhs: Vector4<T>) -> Vector4<T> {
+        self.cols[0].scale_by(rhs.x)
+            + self.cols[1].scale_by(rhs.y)
+            + self.cols[2].scale_by(rhs.z)
+            + self.cols[3].scale_by(rhs.w)
+    }
+}
+
+impl<T: Scalar> ops::Mul for Matrix4<T> {
+    type Output = Self;
+
+    /// Composes two matrices, applying `rhs` first and then `self`.
+    fn mul(self, rhs: Self) -> Self {
+        Matrix4::from_cols(
+            self * rhs.cols[0],
+            self * rhs.cols[1],
+            self * rhs.cols[2],
+            self * rhs.cols[3],
+        )
+    }
+}
+
+impl<T: Scalar> From<Matrix3<T>> for Matrix4<T> {
+
+    /// Embeds a `Matrix3` rotation into the upper-left 3x3 block of a `Matrix4`, with no
+    /// translation and `w` left at `1`. This is what lets a rotation built via
+    /// [`Matrix3::from_axis_angle`]/[`Matrix3::look_at`] combine with
+    /// [`Matrix4::from_translation`]/[`Matrix4::from_scale`] into a single T·R·S transform.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let r = Matrix3::from_axis_angle(Vector3::up(), Deg(90.0));
+    /// let t = Matrix4::from_translation(Vector3::new(0.0, 0.0, 5.0));
+    /// let trs = t * Matrix4::from(r);
+    /// let p = trs * Vector4::new(0.0, 0.0, 1.0, 1.0);
+    ///
+    /// assert!((Vector3::from(p) - Vector3::new(1.0, 0.0, 5.0)).magnitude() < 1e-10);
+    /// ```
+    fn from(m: Matrix3<T>) -> Self {
+        Matrix4::from_cols(
+            Vector4::from(m.cols[0]),
+            Vector4::from(m.cols[1]),
+            Vector4::from(m.cols[2]),
+            Vector4::new(T::zero(), T::zero(), T::zero(), T::one()),
+        )
+    }
+}
@@ -1,7 +1,21 @@
-//! Re-exports everything included in `vector2`, `vector3`, and `vector` into one convenient import.
-//! 
+//! Re-exports the crate's vector, matrix, scalar, and angle types into one convenient import.
+//!
 //! This is done to make it easier for you, the end-user of the library to just get up and running with the default implementations of `Vector`.
 
+pub use angle::{Deg, Rad};
+pub use scalar::Scalar;
 pub use vector2::Vector2;
 pub use vector3::Vector3;
-pub use vector::Vector;
\ No newline at end of file
+pub use vector4::Vector4;
+pub use vector::Vector;
+pub use matrix::{Matrix3, Matrix4};
+
+/// `Vector2<f32>`, for callers who don't need `f64` precision.
+pub type Vec2f = Vector2<f32>;
+/// `Vector2<f64>`, the crate's historical default.
+pub type Vec2d = Vector2<f64>;
+
+/// `Vector3<f32>`, for callers who don't need `f64` precision.
+pub type Vec3f = Vector3<f32>;
+/// `Vector3<f64>`, the crate's historical default.
+pub type Vec3d = Vector3<f64>;
@@ -0,0 +1,163 @@
+//! Trait abstracting over the floating point types usable as vector components.
+
+use std::ops;
+
+/// Bound satisfied by the primitive floating point types (`f32`, `f64`).
+///
+/// This is what lets [`Vector2`](crate::vector2::Vector2) and [`Vector3`](crate::vector3::Vector3)
+/// be generic over their component type while still supporting the handful of
+/// transcendental functions (`sqrt`, `acos`, ...) the `Vector` trait needs.
+pub trait Scalar:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + ops::Add<Output = Self>
+    + ops::AddAssign
+    + ops::Sub<Output = Self>
+    + ops::SubAssign
+    + ops::Mul<Output = Self>
+    + ops::MulAssign
+    + ops::Div<Output = Self>
+    + ops::DivAssign
+    + ops::Neg<Output = Self>
+    + Sized
+{
+    /// The additive identity, `0`.
+    fn zero() -> Self;
+
+    /// The multiplicative identity, `1`.
+    fn one() -> Self;
+
+    /// The positive square root.
+    fn sqrt(self) -> Self;
+
+    /// The arc cosine, in radians.
+    fn acos(self) -> Self;
+
+    /// The sine of an angle given in radians.
+    fn sin(self) -> Self;
+
+    /// The cosine of an angle given in radians.
+    fn cos(self) -> Self;
+
+    /// The tangent of an angle given in radians.
+    fn tan(self) -> Self;
+
+    /// Raises `self` to an integer power.
+    fn powi(self, n: i32) -> Self;
+
+    /// The ratio of a circle's circumference to its diameter.
+    fn pi() -> Self;
+
+    /// Converts a literal `f64` constant into `Self`, e.g. for the `180.0` in a
+    /// degrees/radians conversion.
+    fn from_f64(value: f64) -> Self;
+
+    /// The additive identity, `0`, usable in `const` contexts where [`Scalar::zero`]
+    /// (a function call) can't be.
+    const ZERO: Self;
+
+    /// The multiplicative identity, `1`, usable in `const` contexts where
+    /// [`Scalar::one`] (a function call) can't be.
+    const ONE: Self;
+
+    /// `-1`, usable in `const` contexts.
+    const NEG_ONE: Self;
+
+    /// Not-a-Number.
+    const NAN: Self;
+}
+
+impl Scalar for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+
+    fn acos(self) -> Self {
+        f32::acos(self)
+    }
+
+    fn sin(self) -> Self {
+        f32::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        f32::cos(self)
+    }
+
+    fn tan(self) -> Self {
+        f32::tan(self)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        f32::powi(self, n)
+    }
+
+    fn pi() -> Self {
+        std::f32::consts::PI
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const NEG_ONE: Self = -1.0;
+    const NAN: Self = f32::NAN;
+}
+
+impl Scalar for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+
+    fn acos(self) -> Self {
+        f64::acos(self)
+    }
+
+    fn sin(self) -> Self {
+        f64::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        f64::cos(self)
+    }
+
+    fn tan(self) -> Self {
+        f64::tan(self)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        f64::powi(self, n)
+    }
+
+    fn pi() -> Self {
+        std::f64::consts::PI
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const NEG_ONE: Self = -1.0;
+    const NAN: Self = f64::NAN;
+}
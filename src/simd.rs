@@ -0,0 +1,510 @@
+//! SIMD-accelerated, 16-byte-aligned vector types for batch particle/physics workloads,
+//! gated behind the `simd` feature.
+//!
+//! Unlike [`Vector3`]/[`Vector4`], which are generic over any [`Scalar`](crate::Scalar),
+//! these types are specialized to `f32` (mirroring glam's `Vec3A`/`Vec4`), since a SIMD
+//! lane is a fixed hardware width rather than something that can stay generic. `Vector3A`
+//! has `new`, `splat`, `scale_by`, the `ZERO`/`ONE`/`NEG_ONE`/`NAN`/axis constants, the
+//! arithmetic operators and the [`Vector`] trait; `simd::Vector4` has `new`, `splat`,
+//! `scale_by`, the arithmetic operators and the [`Vector`] trait. The rest of the plain
+//! types' inherent API is not provided: `Vector3A` has no `cross`, `slerp`, direction
+//! shorthands (`up`, `forward`, ...), swizzles, `swap`, indexing or `Sum`/`Product`, and
+//! neither type converts to or from `Vector2`. Code written against the [`Vector`] trait
+//! works unchanged with either type.
+//!
+//! Component-wise `add`/`sub`, multiplication by a scalar (`*`, `scale_by`) and `dot` use
+//! explicit `std::arch` SSE intrinsics on `x86_64` when the `sse2`/`sse3` CPU features are
+//! available, falling back to the portable scalar implementation everywhere else.
+//! [`Vector::scale`] (the component-wise product of two vectors) is plain scalar code.
+
+use std::ops;
+
+use super::prelude::{Vector, Vector3};
+
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::{__m128, _mm_add_ps, _mm_hadd_ps, _mm_loadu_ps, _mm_mul_ps, _mm_storeu_ps, _mm_sub_ps};
+
+/// Adds two 4-float lanes component-wise, using SSE when available.
+fn add4(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("sse2") {
+            return unsafe { simd_op(a, b, _mm_add_ps) };
+        }
+    }
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2], a[3] + b[3]]
+}
+
+/// Subtracts two 4-float lanes component-wise, using SSE when available.
+fn sub4(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("sse2") {
+            return unsafe { simd_op(a, b, _mm_sub_ps) };
+        }
+    }
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2], a[3] - b[3]]
+}
+
+/// Multiplies a 4-float lane by a scalar, using SSE when available.
+fn scale4(a: [f32; 4], s: f32) -> [f32; 4] {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("sse2") {
+            return unsafe { simd_op(a, [s; 4], _mm_mul_ps) };
+        }
+    }
+    [a[0] * s, a[1] * s, a[2] * s, a[3] * s]
+}
+
+/// Returns the dot product of two 4-float lanes, using SSE3's horizontal add when available.
+fn dot4(a: [f32; 4], b: [f32; 4]) -> f32 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("sse3") {
+            return unsafe {
+                let prod = _mm_mul_ps(_mm_loadu_ps(a.as_ptr()), _mm_loadu_ps(b.as_ptr()));
+                let summed = _mm_hadd_ps(prod, prod);
+                let summed = _mm_hadd_ps(summed, summed);
+                let mut out = [0.0f32; 4];
+                _mm_storeu_ps(out.as_mut_ptr(), summed);
+                out[0]
+            };
+        }
+    }
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3]
+}
+
+#[cfg(target_arch = "x86_64")]
+unsafe fn simd_op(a: [f32; 4], b: [f32; 4], op: unsafe fn(__m128, __m128) -> __m128) -> [f32; 4] {
+    let result = op(_mm_loadu_ps(a.as_ptr()), _mm_loadu_ps(b.as_ptr()));
+    let mut out = [0.0f32; 4];
+    _mm_storeu_ps(out.as_mut_ptr(), result);
+    out
+}
+
+/// A 16-byte-aligned 3D vector backed by a 4-wide SIMD lane, with the fourth lane held as
+/// unused padding so `add`/`sub`/`scale_by`/`dot` can dispatch straight to `f32x4` intrinsics.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[repr(align(16))]
+pub struct Vector3A {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    _pad: f32,
+}
+
+impl Vector3A {
+    /// `Vector3A { x: 0.0, y: 0.0, z: 0.0 }`, usable in `const` contexts where
+    /// [`Vector3A::zero`](Vector) (a function call) can't be.
+    pub const ZERO: Self = Vector3A { x: 0.0, y: 0.0, z: 0.0, _pad: 0.0 };
+
+    /// `Vector3A { x: 1.0, y: 1.0, z: 1.0 }`
+    pub const ONE: Self = Vector3A { x: 1.0, y: 1.0, z: 1.0, _pad: 0.0 };
+
+    /// `Vector3A { x: -1.0, y: -1.0, z: -1.0 }`
+    pub const NEG_ONE: Self = Vector3A { x: -1.0, y: -1.0, z: -1.0, _pad: 0.0 };
+
+    /// `Vector3A { x: NaN, y: NaN, z: NaN }`
+    pub const NAN: Self = Vector3A { x: f32::NAN, y: f32::NAN, z: f32::NAN, _pad: 0.0 };
+
+    /// The unit vector along the x-axis, `Vector3A { x: 1.0, y: 0.0, z: 0.0 }`.
+    pub const X: Self = Vector3A { x: 1.0, y: 0.0, z: 0.0, _pad: 0.0 };
+
+    /// The unit vector along the y-axis, `Vector3A { x: 0.0, y: 1.0, z: 0.0 }`.
+    pub const Y: Self = Vector3A { x: 0.0, y: 1.0, z: 0.0, _pad: 0.0 };
+
+    /// The unit vector along the z-axis, `Vector3A { x: 0.0, y: 0.0, z: 1.0 }`.
+    pub const Z: Self = Vector3A { x: 0.0, y: 0.0, z: 1.0, _pad: 0.0 };
+
+    /// Creates a new `Vector3A`.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::simd::Vector3A;
+    /// # use vect::prelude::Vector;
+    /// let a = Vector3A::new(1.0, 2.0, 3.0);
+    /// let b = Vector3A::new(4.0, 5.0, 6.0);
+    ///
+    /// assert_eq!(a + b, Vector3A::new(5.0, 7.0, 9.0));
+    /// assert_eq!(a - b, Vector3A::new(-3.0, -3.0, -3.0));
+    /// assert_eq!(a.scale_by(2.0), Vector3A::new(2.0, 4.0, 6.0));
+    /// assert_eq!(a.dot(&b), 32.0);
+    /// ```
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Vector3A { x, y, z, _pad: 0.0 }
+    }
+
+    /// Creates a new `Vector3A` with every component set to `s`.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::simd::Vector3A;
+    /// assert_eq!(Vector3A::splat(2.0), Vector3A::new(2.0, 2.0, 2.0));
+    /// ```
+    pub fn splat(s: f32) -> Self {
+        Vector3A::new(s, s, s)
+    }
+
+    fn lane(self) -> [f32; 4] {
+        [self.x, self.y, self.z, self._pad]
+    }
+
+    fn from_lane(lane: [f32; 4]) -> Self {
+        Vector3A { x: lane[0], y: lane[1], z: lane[2], _pad: 0.0 }
+    }
+
+    /// Multiplies every component of the vector by `s`.
+    ///
+    /// Same as `self * s`, under the same name as [`Vector3::scale_by`].
+    pub fn scale_by(self, s: f32) -> Self {
+        self * s
+    }
+}
+
+impl ops::Add for Vector3A {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Vector3A::from_lane(add4(self.lane(), other.lane()))
+    }
+}
+
+impl ops::AddAssign for Vector3A {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl ops::Sub for Vector3A {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Vector3A::from_lane(sub4(self.lane(), other.lane()))
+    }
+}
+
+impl ops::SubAssign for Vector3A {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl ops::Mul<f32> for Vector3A {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Vector3A::from_lane(scale4(self.lane(), rhs))
+    }
+}
+
+impl ops::MulAssign<f32> for Vector3A {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl ops::Div<f32> for Vector3A {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        self * (1.0 / rhs)
+    }
+}
+
+impl ops::DivAssign<f32> for Vector3A {
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+
+impl Vector for Vector3A {
+    type Scalar = f32;
+
+    fn zero() -> Self {
+        Vector3A::new(0.0, 0.0, 0.0)
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.sqr_magnitude().sqrt()
+    }
+
+    fn distance(&self, other: &Self) -> f32 {
+        (*self - *other).magnitude()
+    }
+
+    fn normalized(self) -> Self {
+        let mag = self.magnitude();
+        self / mag
+    }
+
+    fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    fn sqr_magnitude(&self) -> f32 {
+        dot4(self.lane(), self.lane())
+    }
+
+    fn angle(&self, other: &Self) -> super::angle::Rad<f32> {
+        let dot = self.dot(other);
+        let mag = self.magnitude() * other.magnitude();
+        super::angle::Rad((dot / mag).acos())
+    }
+
+    fn project(self, other: Self) -> Self {
+        other.scale_by(self.dot(&other) / other.sqr_magnitude())
+    }
+
+    fn clamp_magnitude(self, max_len: f32) -> Self {
+        if self.magnitude() > max_len {
+            self.normalized().scale_by(max_len)
+        } else {
+            self
+        }
+    }
+
+    fn dot(&self, other: &Self) -> f32 {
+        dot4(self.lane(), other.lane())
+    }
+
+    fn scale(self, other: Self) -> Self {
+        Vector3A::new(self.x * other.x, self.y * other.y, self.z * other.z)
+    }
+
+    fn lerp(self, other: Self, t: f32) -> Self {
+        if t <= 0.0 {
+            self
+        } else if t >= 1.0 {
+            other
+        } else {
+            self.lerp_unclamped(other, t)
+        }
+    }
+
+    fn lerp_unclamped(self, other: Self, t: f32) -> Self {
+        self.scale_by(1.0 - t) + other.scale_by(t)
+    }
+
+    fn move_towards(self, other: Self, max_distance_delta: f32) -> Self {
+        let distance = self.distance(&other);
+        let fraction = max_distance_delta / distance;
+        self.lerp_unclamped(other, fraction)
+    }
+
+    fn reflect(self, normal: Self) -> Self {
+        self - normal.scale_by(2.0 * self.dot(&normal))
+    }
+}
+
+impl From<Vector3<f32>> for Vector3A {
+    fn from(v: Vector3<f32>) -> Self {
+        Vector3A::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3A> for Vector3<f32> {
+    fn from(v: Vector3A) -> Self {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+/// A 16-byte-aligned 4D vector whose `add`/`sub`/`scale_by`/`dot` dispatch straight to `f32x4`
+/// intrinsics, with no padding needed since all four lanes are already in use.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[repr(align(16))]
+pub struct Vector4 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Vector4 {
+    /// Creates a new `Vector4`.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::simd::Vector4;
+    /// # use vect::prelude::Vector;
+    /// let a = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// let b = Vector4::new(5.0, 6.0, 7.0, 8.0);
+    ///
+    /// assert_eq!(a + b, Vector4::new(6.0, 8.0, 10.0, 12.0));
+    /// assert_eq!(a.dot(&b), 70.0);
+    /// ```
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Vector4 { x, y, z, w }
+    }
+
+    /// Creates a new `Vector4` with every component set to `s`.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::simd::Vector4;
+    /// assert_eq!(Vector4::splat(2.0), Vector4::new(2.0, 2.0, 2.0, 2.0));
+    /// ```
+    pub fn splat(s: f32) -> Self {
+        Vector4::new(s, s, s, s)
+    }
+
+    fn lane(self) -> [f32; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    fn from_lane(lane: [f32; 4]) -> Self {
+        Vector4::new(lane[0], lane[1], lane[2], lane[3])
+    }
+
+    /// Multiplies every component of the vector by `s`.
+    pub fn scale_by(self, s: f32) -> Self {
+        self * s
+    }
+}
+
+impl ops::Add for Vector4 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Vector4::from_lane(add4(self.lane(), other.lane()))
+    }
+}
+
+impl ops::AddAssign for Vector4 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl ops::Sub for Vector4 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Vector4::from_lane(sub4(self.lane(), other.lane()))
+    }
+}
+
+impl ops::SubAssign for Vector4 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl ops::Mul<f32> for Vector4 {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Vector4::from_lane(scale4(self.lane(), rhs))
+    }
+}
+
+impl ops::MulAssign<f32> for Vector4 {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl ops::Div<f32> for Vector4 {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        self * (1.0 / rhs)
+    }
+}
+
+impl ops::DivAssign<f32> for Vector4 {
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+
+impl Vector for Vector4 {
+    type Scalar = f32;
+
+    fn zero() -> Self {
+        Vector4::new(0.0, 0.0, 0.0, 0.0)
+    }
+
+    fn magnitude(&self) -> f32 {
+        self.sqr_magnitude().sqrt()
+    }
+
+    fn distance(&self, other: &Self) -> f32 {
+        (*self - *other).magnitude()
+    }
+
+    fn normalized(self) -> Self {
+        let mag = self.magnitude();
+        self / mag
+    }
+
+    fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    fn sqr_magnitude(&self) -> f32 {
+        dot4(self.lane(), self.lane())
+    }
+
+    fn angle(&self, other: &Self) -> super::angle::Rad<f32> {
+        let dot = self.dot(other);
+        let mag = self.magnitude() * other.magnitude();
+        super::angle::Rad((dot / mag).acos())
+    }
+
+    fn project(self, other: Self) -> Self {
+        other.scale_by(self.dot(&other) / other.sqr_magnitude())
+    }
+
+    fn clamp_magnitude(self, max_len: f32) -> Self {
+        if self.magnitude() > max_len {
+            self.normalized().scale_by(max_len)
+        } else {
+            self
+        }
+    }
+
+    fn dot(&self, other: &Self) -> f32 {
+        dot4(self.lane(), other.lane())
+    }
+
+    fn scale(self, other: Self) -> Self {
+        Vector4::new(self.x * other.x, self.y * other.y, self.z * other.z, self.w * other.w)
+    }
+
+    fn lerp(self, other: Self, t: f32) -> Self {
+        if t <= 0.0 {
+            self
+        } else if t >= 1.0 {
+            other
+        } else {
+            self.lerp_unclamped(other, t)
+        }
+    }
+
+    fn lerp_unclamped(self, other: Self, t: f32) -> Self {
+        self.scale_by(1.0 - t) + other.scale_by(t)
+    }
+
+    fn move_towards(self, other: Self, max_distance_delta: f32) -> Self {
+        let distance = self.distance(&other);
+        let fraction = max_distance_delta / distance;
+        self.lerp_unclamped(other, fraction)
+    }
+
+    fn reflect(self, normal: Self) -> Self {
+        self - normal.scale_by(2.0 * self.dot(&normal))
+    }
+}
+
+impl From<super::vector4::Vector4<f32>> for Vector4 {
+    fn from(v: super::vector4::Vector4<f32>) -> Self {
+        Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Vector4> for super::vector4::Vector4<f32> {
+    fn from(v: Vector4) -> Self {
+        super::vector4::Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
@@ -2,19 +2,22 @@
 
 use std::ops;
 
+use super::angle::Rad;
+use super::scalar::Scalar;
+
 /// Base Vector trait.
-pub trait Vector: 
-    ops::Add 
-    + ops::AddAssign 
-    + ops::Sub 
+pub trait Vector:
+    ops::Add<Output = Self>
+    + ops::AddAssign
+    + ops::Sub<Output = Self>
     + ops::SubAssign
-    + PartialEq 
+    + PartialEq
     + PartialOrd
-    + Sized 
+    + Sized
 {
     // Associated type, which sets the scalar type of a given implementation.
     // A scalar is just a regular non-vector number.
-    type Scalar;
+    type Scalar: Scalar;
 
     /// The Zero vector
     fn zero() -> Self;
@@ -35,7 +38,7 @@ pub trait Vector:
     fn sqr_magnitude(&self) -> Self::Scalar;
 
     /// Returns the angle between two vectors
-    fn angle(&self, other: &Self) -> Self::Scalar;
+    fn angle(&self, other: &Self) -> Rad<Self::Scalar>;
 
     /// Projects the vector onto the other vector
     fn project(self, other: Self) -> Self;
@@ -50,18 +53,18 @@ pub trait Vector:
     fn scale(self, other: Self) -> Self;
 
     /// Performs a linear interpolation between `self` and `other` over `t`.
-    /// 
+    ///
     /// `t` is clamped to the range [0, 1].
-    /// 
+    ///
     /// * when `t` = 0, it returns `self`
     /// * when `t` = 0.5, it returns a vector half-way between `self` and `other`
     /// * when `t` = 1, it returns `other`.
-    /// 
+    ///
     /// Lerp guarantees the interpolation will never exceed the range [0, 1] for `t`
     fn lerp(self, other: Self, t: Self::Scalar) -> Self;
 
     /// Performs a linear interpolation, where `t` isn't clamped between 0 and 1.
-    /// 
+    ///
     /// Provides no guarantees on the interpolation.
     fn lerp_unclamped(self, other: Self, t: Self::Scalar) -> Self;
 
@@ -1,63 +1,177 @@
 //! Standard implementation of a 2D Vector.
-//! 
-//! This particular implementation uses 64-bit floating point numbers as its scalar components. 
-//! It does so to ease compatibility with [piston.rs](https://www.piston.rs/), as that is what it uses by default for its scalars.
+//!
+//! Generic over its scalar component type `T` (anything implementing [`Scalar`]),
+//! so callers can pick `Vector2<f32>` for graphics work or `Vector2<f64>` for
+//! simulation without forking the crate. See [`Vec2f`](crate::prelude::Vec2f) and
+//! [`Vec2d`](crate::prelude::Vec2d) in the prelude for the common aliases.
 
+use std::iter;
 use std::ops;
-use super::prelude::{Vector, Vector3};
-
-
+use super::prelude::{Rad, Scalar, Vector, Vector3};
+
+
+/// With the `serde` feature enabled, `Vector2` round-trips through any serde data format:
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// # use vect::prelude::*;
+/// let v = Vector2::new(1.0, 2.0);
+/// let json = serde_json::to_string(&v).unwrap();
+/// let back: Vector2 = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(v, back);
+/// # }
+/// ```
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
-pub struct Vector2 {
-    pub x: f64,
-    pub y: f64,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector2<T: Scalar = f64> {
+    pub x: T,
+    pub y: T,
 }
 
-impl Vector2 {
+impl<T: Scalar> Vector2<T> {
+
+    /// `Vector2 { x: 0.0, y: 0.0 }`, usable in `const` contexts where [`Vector2::zero`](Vector)
+    /// (a function call) can't be.
+    pub const ZERO: Self = Vector2 { x: T::ZERO, y: T::ZERO };
+
+    /// `Vector2 { x: 1.0, y: 1.0 }`
+    pub const ONE: Self = Vector2 { x: T::ONE, y: T::ONE };
+
+    /// `Vector2 { x: -1.0, y: -1.0 }`
+    pub const NEG_ONE: Self = Vector2 { x: T::NEG_ONE, y: T::NEG_ONE };
+
+    /// `Vector2 { x: NaN, y: NaN }`
+    pub const NAN: Self = Vector2 { x: T::NAN, y: T::NAN };
+
+    /// The unit vector along the x-axis, `Vector2 { x: 1.0, y: 0.0 }`.
+    pub const X: Self = Vector2 { x: T::ONE, y: T::ZERO };
+
+    /// The unit vector along the y-axis, `Vector2 { x: 0.0, y: 1.0 }`.
+    pub const Y: Self = Vector2 { x: T::ZERO, y: T::ONE };
 
     /// Creates a new `Vector2`
-    pub fn new(x: f64, y: f64) -> Self {
+    pub fn new(x: T, y: T) -> Self {
         Vector2 { x, y }
     }
 
+    /// Creates a new `Vector2` with every component set to `s`.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// assert_eq!(Vector2::splat(2.0), Vector2::new(2.0, 2.0));
+    ///
+    /// assert_eq!(Vector2::<f64>::ZERO, Vector2::new(0.0, 0.0));
+    /// assert_eq!(Vector2::<f64>::ONE, Vector2::new(1.0, 1.0));
+    /// assert_eq!(Vector2::<f64>::NEG_ONE, Vector2::new(-1.0, -1.0));
+    /// assert_eq!(Vector2::<f64>::X, Vector2::new(1.0, 0.0));
+    /// assert_eq!(Vector2::<f64>::Y, Vector2::new(0.0, 1.0));
+    /// assert!(Vector2::<f64>::NAN.x.is_nan());
+    /// ```
+    pub fn splat(s: T) -> Self {
+        Vector2 { x: s, y: s }
+    }
+
     /// Shorthand for `Vector2 { x: 0.0, y: 1.0 }`
     pub fn up() -> Self {
         Vector2 {
-            x: 0.0,
-            y: 1.0,
+            x: T::zero(),
+            y: T::one(),
         }
     }
 
     /// Shorthand for `Vector2 { x: 0.0, y: -1.0 }`
     pub fn down() -> Self {
         Vector2 {
-            x: 0.0,
-            y: -1.0,
+            x: T::zero(),
+            y: -T::one(),
         }
     }
 
     /// Shorthand for `Vector2 { x: -1.0, y: 0.0 }`
     pub fn left() -> Self {
         Vector2 {
-            x: -1.0,
-            y: 0.0,
+            x: -T::one(),
+            y: T::zero(),
         }
     }
 
     /// Shorthand for `Vector2 { x: 1.0, y: 0.0 }`
     pub fn right() -> Self {
         Vector2 {
-            x: 1.0,
-            y: 0.0,
+            x: T::one(),
+            y: T::zero(),
         }
     }
+
+    /// Returns the distance between two vectors.
+    ///
+    /// `a.distance(b)` is the same as `(a - b).magnitude()`.
+    pub fn distance(&self, other: &Self) -> T {
+        (*self - *other).magnitude()
+    }
+
+    /// Multiplies every component of the vector by `s`.
+    ///
+    /// This is the scalar-on-the-left direction of `Vector2 * T`; it exists as an
+    /// inherent method because a generic `impl<T: Scalar> Mul<Vector2<T>> for T` would
+    /// violate the orphan rules (`T` isn't a type this crate owns).
+    pub fn scale_by(self, s: T) -> Self {
+        self * s
+    }
+
+    /// Swaps two components of the vector in place, addressed the same way as [`Index`](ops::Index).
+    ///
+    /// Panics if either index is out of range.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let mut v = Vector2::new(1.0, 2.0);
+    /// v.swap(0, 1);
+    ///
+    /// assert_eq!(v, Vector2::new(2.0, 1.0));
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let tmp = self[a];
+        self[a] = self[b];
+        self[b] = tmp;
+    }
+
+    /// Swizzle returning `Vector2 { x, y }` (identity).
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let v = Vector2::new(1.0, 2.0);
+    ///
+    /// assert_eq!(v.xy(), Vector2::new(1.0, 2.0));
+    /// ```
+    pub fn xy(self) -> Vector2<T> {
+        Vector2::new(self.x, self.y)
+    }
+
+    /// Swizzle returning `Vector2 { x: y, y: x }`.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let v = Vector2::new(1.0, 2.0);
+    ///
+    /// assert_eq!(v.yx(), Vector2::new(2.0, 1.0));
+    /// ```
+    pub fn yx(self) -> Vector2<T> {
+        Vector2::new(self.y, self.x)
+    }
 }
 
-impl ops::Add for Vector2 {
+impl<T: Scalar> ops::Add for Vector2<T> {
     type Output = Self;
 
     /// Adds two vectors together.
-    /// 
+    ///
     /// Example:
     /// ```
     /// # use vect::prelude::*;
@@ -65,7 +179,7 @@ impl ops::Add for Vector2 {
     /// let b = Vector2::new(3.0, 4.0);
     /// let res = a + b;
     /// let expected = Vector2::new(4.0, 6.0);
-    /// 
+    ///
     /// assert_eq!(res, expected);
     /// ```
     fn add(self, other: Self) -> Self {
@@ -76,10 +190,10 @@ impl ops::Add for Vector2 {
     }
 }
 
-impl ops::AddAssign for Vector2 {
+impl<T: Scalar> ops::AddAssign for Vector2<T> {
 
     /// Adds two vectors together and assigns the result back to the first.
-    /// 
+    ///
     /// Example:
     /// ```
     /// # use vect::prelude::*;
@@ -87,7 +201,7 @@ impl ops::AddAssign for Vector2 {
     /// let b = Vector2::new(3.0, 4.0);
     /// a += b;
     /// let expected = Vector2::new(4.0, 6.0);
-    /// 
+    ///
     /// assert_eq!(a, expected);
     /// ```
     fn add_assign(&mut self, other: Self) {
@@ -95,11 +209,11 @@ impl ops::AddAssign for Vector2 {
     }
 }
 
-impl ops::Sub for Vector2 {
+impl<T: Scalar> ops::Sub for Vector2<T> {
     type Output = Self;
 
     /// Subtracts two vectors from each other.
-    /// 
+    ///
     /// Example:
     /// ```
     /// # use vect::prelude::*;
@@ -107,7 +221,7 @@ impl ops::Sub for Vector2 {
     /// let b = Vector2::new(6.0, 4.0);
     /// let res = a - b;
     /// let expected = Vector2::new(-1.0, 4.0);
-    /// 
+    ///
     /// assert_eq!(res, expected);
     /// ```
     fn sub(self, other: Self) -> Self {
@@ -118,10 +232,10 @@ impl ops::Sub for Vector2 {
     }
 }
 
-impl ops::SubAssign for Vector2 {
+impl<T: Scalar> ops::SubAssign for Vector2<T> {
 
     /// Subtracts two vectors from each other and assigns the result back to the first.
-    /// 
+    ///
     /// Example:
     /// ```
     /// # use vect::prelude::*;
@@ -129,7 +243,7 @@ impl ops::SubAssign for Vector2 {
     /// let b = Vector2::new(6.0, 4.0);
     /// a -= b;
     /// let expected = Vector2::new(-1.0, 4.0);
-    /// 
+    ///
     /// assert_eq!(a, expected);
     /// ```
     fn sub_assign(&mut self, other: Self) {
@@ -137,21 +251,21 @@ impl ops::SubAssign for Vector2 {
     }
 }
 
-impl ops::Mul<f64> for Vector2 {
+impl<T: Scalar> ops::Mul<T> for Vector2<T> {
     type Output = Self;
 
     /// Multiplies the vector with some scalar
-    /// 
+    ///
     /// Example:
     /// ```
     /// # use vect::prelude::*;
     /// let a = Vector2::new(3.0, 4.0);
     /// let res = a * 3.0;
     /// let expected = Vector2::new(9.0, 12.0);
-    /// 
+    ///
     /// assert_eq!(res, expected);
     /// ```
-    fn mul(self, rhs: f64) -> Self {
+    fn mul(self, rhs: T) -> Self {
         Vector2 {
             x: self.x * rhs,
             y: self.y * rhs,
@@ -159,51 +273,29 @@ impl ops::Mul<f64> for Vector2 {
     }
 }
 
-impl ops::MulAssign<f64> for Vector2 {
+impl<T: Scalar> ops::MulAssign<T> for Vector2<T> {
 
     /// Multiplies the vector with some scalar and assigns the result back onto the vector
-    /// 
+    ///
     /// Example:
     /// ```
     /// # use vect::prelude::*;
     /// let mut a = Vector2::new(3.0, 4.0);
     /// a *= 3.0;
     /// let expected = Vector2::new(9.0, 12.0);
-    /// 
+    ///
     /// assert_eq!(a, expected);
     /// ```
-    fn mul_assign(&mut self, other: f64) {
+    fn mul_assign(&mut self, other: T) {
         *self = *self * other;
     }
 }
 
-impl ops::Mul<Vector2> for f64 {
-    type Output = Vector2;
-
-    /// Multiplies a scalar with some vector
-    /// 
-    /// Example:
-    /// ```
-    /// # use vect::prelude::*;
-    /// let a = Vector2::new(2.5, 5.0);
-    /// let res = 2.0 * a;
-    /// let expected = Vector2::new(5.0, 10.0);
-    /// 
-    /// assert_eq!(res, expected);
-    /// ```
-    fn mul(self, rhs: Vector2) -> Vector2 {
-        Vector2 {
-            x: self * rhs.x,
-            y: self * rhs.y,
-        }
-    }
-}
-
-impl ops::Div<f64> for Vector2 {
+impl<T: Scalar> ops::Div<T> for Vector2<T> {
     type Output = Self;
 
     /// Divides the vector with some scalar
-    fn div(self, other: f64) -> Self {
+    fn div(self, other: T) -> Self {
         Vector2 {
             x: self.x / other,
             y: self.y / other,
@@ -211,33 +303,21 @@ impl ops::Div<f64> for Vector2 {
     }
 }
 
-impl ops::DivAssign<f64> for Vector2 {
+impl<T: Scalar> ops::DivAssign<T> for Vector2<T> {
 
     /// Divides the vector with some scalar and assigns the result back into the vector
-    fn div_assign(&mut self, other: f64) {
+    fn div_assign(&mut self, other: T) {
         *self = *self / other;
     }
 }
 
-impl ops::Div<Vector2> for f64 {
-    type Output = Vector2;
-
-    /// Divides a scalar with some vector
-    fn div(self, other: Vector2) -> Vector2 {
-        Vector2 {
-            x: self / other.x,
-            y: self / other.y,
-        }
-    }
-}
-
-impl Vector for Vector2 {
-    type Scalar = f64;
+impl<T: Scalar> Vector for Vector2<T> {
+    type Scalar = T;
 
     fn zero() -> Self {
         Vector2 {
-            x: 0.0,
-            y: 0.0,
+            x: T::zero(),
+            y: T::zero(),
         }
     }
 
@@ -245,6 +325,10 @@ impl Vector for Vector2 {
         self.sqr_magnitude().sqrt()
     }
 
+    fn distance(&self, other: &Self) -> Self::Scalar {
+        Vector2::distance(self, other)
+    }
+
     fn normalized(self) -> Self {
         let mag = self.magnitude();
         self / mag
@@ -255,13 +339,17 @@ impl Vector for Vector2 {
     }
 
     fn sqr_magnitude(&self) -> Self::Scalar {
-        (self.x.powi(2) + self.y.powi(2))
+        self.x.powi(2) + self.y.powi(2)
     }
 
-    fn angle(&self, other: &Self) -> Self::Scalar {
+    fn angle(&self, other: &Self) -> Rad<Self::Scalar> {
         let dot = self.dot(other);
         let mag = self.magnitude() * other.magnitude();
-        (dot / mag).acos()
+        Rad((dot / mag).acos())
+    }
+
+    fn project(self, other: Self) -> Self {
+        other.scale_by(self.dot(&other) / other.sqr_magnitude())
     }
 
     fn clamp_magnitude(self, max_len: Self::Scalar) -> Self {
@@ -284,9 +372,9 @@ impl Vector for Vector2 {
     }
 
     fn lerp(self, other: Self, t: Self::Scalar) -> Self {
-        if t <= 0.0 {
+        if t <= T::zero() {
             self
-        } else if t >= 1.0 {
+        } else if t >= T::one() {
             other
         } else {
             self.lerp_unclamped(other, t)
@@ -294,36 +382,115 @@ impl Vector for Vector2 {
     }
 
     fn lerp_unclamped(self, other: Self, t: Self::Scalar) -> Self {
-        (1.0 - t) * self + t * other
+        self.scale_by(T::one() - t) + other.scale_by(t)
     }
 
-    // fn move_towards(self, other: Self, max_distance_delta: Self::Scalar) -> Self {
-    //     unimplemented!("Unsure how this is supposed to be implemented");
-    // }
+    fn move_towards(self, other: Self, max_distance_delta: Self::Scalar) -> Self {
+        let distance = self.distance(&other);
+        let fraction = max_distance_delta / distance;
+        self.lerp_unclamped(other, fraction)
+    }
 
     /// Reflects the vector along the `normal` vector.
-    /// 
+    ///
     /// Example:
-    /// 
+    ///
     /// ```
     /// # use vect::prelude::*;
     /// let a = Vector2::new(1.0, 2.0);
     /// let n = Vector2::up();
     /// let r = a.reflect(n);
-    /// 
+    ///
     /// assert_eq!(r, Vector2::new(1.0, -2.0));
     /// ```
     fn reflect(self, normal: Self) -> Self {
-        -2.0 * self.dot(&normal) * normal + self
+        let two = T::one() + T::one();
+        self - normal.scale_by(two * self.dot(&normal))
     }
 }
 
-impl From<Vector3> for Vector2 {
+impl<T: Scalar> From<Vector3<T>> for Vector2<T> {
     /// Turns a `Vector3` into a `Vector2`, discarding the z component.
-    fn from(vector: Vector3) -> Vector2 {
+    fn from(vector: Vector3<T>) -> Vector2<T> {
         Vector2 {
             x: vector.x,
             y: vector.y,
         }
     }
-}
\ No newline at end of file
+}
+
+impl<T: Scalar> ops::Index<usize> for Vector2<T> {
+    type Output = T;
+
+    /// Indexes into the vector's components, `0` for `x` and `1` for `y`.
+    ///
+    /// Panics if `index` is out of range.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let v = Vector2::new(1.0, 2.0);
+    ///
+    /// assert_eq!(v[0], 1.0);
+    /// assert_eq!(v[1], 2.0);
+    /// ```
+    ///
+    /// Out-of-range access panics:
+    /// ```should_panic
+    /// # use vect::prelude::*;
+    /// let v = Vector2::new(1.0, 2.0);
+    /// let _ = v[2];
+    /// ```
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("index out of range for Vector2: {}", index),
+        }
+    }
+}
+
+impl<T: Scalar> ops::IndexMut<usize> for Vector2<T> {
+    /// Mutably indexes into the vector's components, `0` for `x` and `1` for `y`.
+    ///
+    /// Panics if `index` is out of range.
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("index out of range for Vector2: {}", index),
+        }
+    }
+}
+
+impl<T: Scalar> iter::Sum for Vector2<T> {
+    /// Sums an iterator of vectors, e.g. for computing a centroid with `sum() / n`.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let points = [Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0), Vector2::new(2.0, 2.0)];
+    /// let centroid = points.iter().copied().sum::<Vector2<f64>>() / points.len() as f64;
+    ///
+    /// assert_eq!(centroid, Vector2::new(1.0, 1.0));
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Vector2::zero(), |a, b| a + b)
+    }
+}
+
+impl<T: Scalar> iter::Product for Vector2<T> {
+    /// Multiplies an iterator of vectors component-wise.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let factors = [Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)];
+    /// let product = factors.iter().copied().product::<Vector2<f64>>();
+    ///
+    /// assert_eq!(product, Vector2::new(3.0, 8.0));
+    /// ```
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Vector2::splat(T::one()), |a, b| a.scale(b))
+    }
+}
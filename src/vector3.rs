@@ -1,30 +1,88 @@
 //! Standard implementation of a 3D Vector.
-//! 
-//! This particular implementation uses 64-bit floating point numbers as its scalar components. 
-//! It does so to ease compatibility with [piston.rs](https://www.piston.rs/), as that is what it uses by default for its scalars.
+//!
+//! Generic over its scalar component type `T` (anything implementing [`Scalar`]),
+//! so callers can pick `Vector3<f32>` for graphics work or `Vector3<f64>` for
+//! simulation without forking the crate. See [`Vec3f`](crate::prelude::Vec3f) and
+//! [`Vec3d`](crate::prelude::Vec3d) in the prelude for the common aliases.
 
+use std::iter;
 use std::ops;
 
-use super::prelude::{Vector, Vector2};
-
+use super::prelude::{Rad, Scalar, Vector, Vector2};
+
+/// With the `serde` feature enabled, `Vector3` round-trips through any serde data format:
+///
+/// ```
+/// # #[cfg(feature = "serde")]
+/// # {
+/// # use vect::prelude::*;
+/// let v = Vector3::new(1.0, 2.0, 3.0);
+/// let json = serde_json::to_string(&v).unwrap();
+/// let back: Vector3 = serde_json::from_str(&json).unwrap();
+///
+/// assert_eq!(v, back);
+/// # }
+/// ```
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
-pub struct Vector3 {
-    pub x: f64,
-    pub y: f64,
-    pub z: f64,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector3<T: Scalar = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
 }
 
-impl Vector3 {
+impl<T: Scalar> Vector3<T> {
+
+    /// `Vector3 { x: 0.0, y: 0.0, z: 0.0 }`, usable in `const` contexts where
+    /// [`Vector3::zero`](Vector) (a function call) can't be.
+    pub const ZERO: Self = Vector3 { x: T::ZERO, y: T::ZERO, z: T::ZERO };
+
+    /// `Vector3 { x: 1.0, y: 1.0, z: 1.0 }`
+    pub const ONE: Self = Vector3 { x: T::ONE, y: T::ONE, z: T::ONE };
+
+    /// `Vector3 { x: -1.0, y: -1.0, z: -1.0 }`
+    pub const NEG_ONE: Self = Vector3 { x: T::NEG_ONE, y: T::NEG_ONE, z: T::NEG_ONE };
+
+    /// `Vector3 { x: NaN, y: NaN, z: NaN }`
+    pub const NAN: Self = Vector3 { x: T::NAN, y: T::NAN, z: T::NAN };
+
+    /// The unit vector along the x-axis, `Vector3 { x: 1.0, y: 0.0, z: 0.0 }`.
+    pub const X: Self = Vector3 { x: T::ONE, y: T::ZERO, z: T::ZERO };
+
+    /// The unit vector along the y-axis, `Vector3 { x: 0.0, y: 1.0, z: 0.0 }`.
+    pub const Y: Self = Vector3 { x: T::ZERO, y: T::ONE, z: T::ZERO };
+
+    /// The unit vector along the z-axis, `Vector3 { x: 0.0, y: 0.0, z: 1.0 }`.
+    pub const Z: Self = Vector3 { x: T::ZERO, y: T::ZERO, z: T::ONE };
 
     /// Creates a new `Vector3`
-    pub fn new(x: f64, y: f64, z: f64) -> Self {
+    pub fn new(x: T, y: T, z: T) -> Self {
         Vector3 { x, y, z }
     }
 
+    /// Creates a new `Vector3` with every component set to `s`.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// assert_eq!(Vector3::splat(2.0), Vector3::new(2.0, 2.0, 2.0));
+    ///
+    /// assert_eq!(Vector3::<f64>::ZERO, Vector3::new(0.0, 0.0, 0.0));
+    /// assert_eq!(Vector3::<f64>::ONE, Vector3::new(1.0, 1.0, 1.0));
+    /// assert_eq!(Vector3::<f64>::NEG_ONE, Vector3::new(-1.0, -1.0, -1.0));
+    /// assert_eq!(Vector3::<f64>::X, Vector3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(Vector3::<f64>::Y, Vector3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(Vector3::<f64>::Z, Vector3::new(0.0, 0.0, 1.0));
+    /// assert!(Vector3::<f64>::NAN.x.is_nan());
+    /// ```
+    pub fn splat(s: T) -> Self {
+        Vector3 { x: s, y: s, z: s }
+    }
+
     /// Shorthand for `Vector3 { x: 0.0, y: 1.0, z: 0.0 }`
     pub fn up() -> Self {
         Vector3 {
-            y: 1.0,
+            y: T::one(),
             .. Vector3::zero()
         }
     }
@@ -32,7 +90,7 @@ impl Vector3 {
     /// Shorthand for `Vector3 { x: 0.0, y: -1.0, z: 0.0 }`
     pub fn down() -> Self {
         Vector3 {
-            y: -1.0,
+            y: -T::one(),
             .. Vector3::zero()
         }
     }
@@ -40,7 +98,7 @@ impl Vector3 {
     /// Shorthand for `Vector3 { x: -1.0, y: 0.0, z: 0.0 }`
     pub fn left() -> Self {
         Vector3 {
-            x: -1.0,
+            x: -T::one(),
             .. Vector3::zero()
         }
     }
@@ -48,7 +106,7 @@ impl Vector3 {
     /// Shorthand for `Vector3 { x: 1.0, y: 0.0, z: 0.0 }`
     pub fn right() -> Self {
         Vector3 {
-            x: 1.0,
+            x: T::one(),
             .. Vector3::zero()
         }
     }
@@ -56,7 +114,7 @@ impl Vector3 {
     /// Shorthand for `Vector3 {x: 0.0, y: 0.0, z: 1.0 }`
     pub fn forward() -> Self {
         Vector3 {
-            z: 1.0,
+            z: T::one(),
             .. Vector3::zero()
         }
     }
@@ -64,13 +122,13 @@ impl Vector3 {
     /// Shorthand for `Vector3 {x: 0.0, y: 0.0, z: -1.0 }`
     pub fn back() -> Self {
         Vector3 {
-            z: -1.0,
+            z: -T::one(),
             .. Vector3::zero()
         }
     }
 
     /// Defines the cross product between two vectors
-    /// 
+    ///
     /// Example:
     /// ```
     /// # use vect::prelude::*;
@@ -78,7 +136,7 @@ impl Vector3 {
     /// let b = Vector3::new(4.0, 9.0, 2.0);
     /// let res = a.cross(b);
     /// let expected = Vector3::new(-15.0, -2.0, 39.0);
-    /// 
+    ///
     /// assert_eq!(res, expected);
     /// ```
     pub fn cross(self, other: Self) -> Self {
@@ -90,21 +148,30 @@ impl Vector3 {
     }
 
     /// Returns the distance between two vectors.
-    /// 
+    ///
     /// `a.distance(b)` is the same as `(a - b).magnitude()`.
-    pub fn distance(&self, other: &Self) -> f64 {
+    pub fn distance(&self, other: &Self) -> T {
         (*self - *other).magnitude()
     }
 
+    /// Multiplies every component of the vector by `s`.
+    ///
+    /// This is the scalar-on-the-left direction of `Vector3 * T`; it exists as an
+    /// inherent method because a generic `impl<T: Scalar> Mul<Vector3<T>> for T` would
+    /// violate the orphan rules (`T` isn't a type this crate owns).
+    pub fn scale_by(self, s: T) -> Self {
+        self * s
+    }
+
     /// Returns a new vector that is spherically lerped with relation to t.
     /// Where t is clamped in the range [0, 1]
-    /// 
+    ///
     /// See [Wikipedia](https://en.wikipedia.org/wiki/Slerp#Geometric_Slerp) for the method in which this was calculated.
-    pub fn slerp(self, other: Self, t: f64) -> Self {
+    pub fn slerp(self, other: Self, t: T) -> Self {
         // ensure t stays within bounds
-        if t <= 0.0 {
+        if t <= T::zero() {
             self
-        } else if t >= 1.0 {
+        } else if t >= T::one() {
             other
         } else {
             self.slerp_unclamped(other, t)
@@ -113,17 +180,87 @@ impl Vector3 {
 
     /// Unclamped version of slerp.
     /// Doesn't provide any guarantees on the input
-    pub fn slerp_unclamped(self, other: Self, t: f64) -> Self {
+    pub fn slerp_unclamped(self, other: Self, t: T) -> Self {
         // if cos Ω = p1 dot p2; that must mean Ω = acos (p1 dot p2)
         let omega = self.dot(&other).acos();
-        let lhs = (((1.0 - t) * omega).sin() * self) / omega.sin();
-        let rhs = ((t * omega).sin() * other) / omega.sin();
+        let lhs = self.scale_by(((T::one() - t) * omega).sin()) / omega.sin();
+        let rhs = other.scale_by((t * omega).sin()) / omega.sin();
 
         lhs + rhs
     }
+
+    /// Swaps two components of the vector in place, addressed the same way as [`Index`](ops::Index).
+    ///
+    /// Panics if either index is out of range.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let mut v = Vector3::new(1.0, 2.0, 3.0);
+    /// v.swap(0, 2);
+    ///
+    /// assert_eq!(v, Vector3::new(3.0, 2.0, 1.0));
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let tmp = self[a];
+        self[a] = self[b];
+        self[b] = tmp;
+    }
+
+    /// Swizzle returning `Vector2 { x, y }`.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(v.xy(), Vector2::new(1.0, 2.0));
+    /// ```
+    pub fn xy(self) -> Vector2<T> {
+        Vector2::new(self.x, self.y)
+    }
+
+    /// Swizzle returning `Vector2 { x: y, y: x }`.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(v.yx(), Vector2::new(2.0, 1.0));
+    /// ```
+    pub fn yx(self) -> Vector2<T> {
+        Vector2::new(self.y, self.x)
+    }
+
+    /// Swizzle returning `Vector2 { x, y: z }`.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(v.xz(), Vector2::new(1.0, 3.0));
+    /// ```
+    pub fn xz(self) -> Vector2<T> {
+        Vector2::new(self.x, self.z)
+    }
+
+    /// Swizzle returning `Vector3 { x: z, y, z: x }`.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(v.zyx(), Vector3::new(3.0, 2.0, 1.0));
+    /// ```
+    pub fn zyx(self) -> Vector3<T> {
+        Vector3::new(self.z, self.y, self.x)
+    }
 }
 
-impl ops::Add for Vector3 {
+impl<T: Scalar> ops::Add for Vector3<T> {
     type Output = Self;
 
     /// Adds two vectors together
@@ -136,7 +273,7 @@ impl ops::Add for Vector3 {
     }
 }
 
-impl ops::AddAssign for Vector3 {
+impl<T: Scalar> ops::AddAssign for Vector3<T> {
 
     /// Adds two vectors together, and assigns the result into the first vector
     fn add_assign(&mut self, other: Self) {
@@ -144,7 +281,7 @@ impl ops::AddAssign for Vector3 {
     }
 }
 
-impl ops::Sub for Vector3 {
+impl<T: Scalar> ops::Sub for Vector3<T> {
     type Output = Self;
 
     /// Subtracts two vectors from each other
@@ -157,7 +294,7 @@ impl ops::Sub for Vector3 {
     }
 }
 
-impl ops::SubAssign for Vector3 {
+impl<T: Scalar> ops::SubAssign for Vector3<T> {
 
     /// Subtracts two vectors from each other, and assigns the result into the first vector
     fn sub_assign(&mut self, other: Self) {
@@ -165,11 +302,11 @@ impl ops::SubAssign for Vector3 {
     }
 }
 
-impl ops::Mul<f64> for Vector3 {
+impl<T: Scalar> ops::Mul<T> for Vector3<T> {
     type Output = Self;
 
     /// Multiplies a vector with a scalar
-    fn mul(self, rhs: f64) -> Self {
+    fn mul(self, rhs: T) -> Self {
         Vector3 {
             x: self.x * rhs,
             y: self.y * rhs,
@@ -178,33 +315,19 @@ impl ops::Mul<f64> for Vector3 {
     }
 }
 
-impl ops::MulAssign<f64> for Vector3 {
+impl<T: Scalar> ops::MulAssign<T> for Vector3<T> {
 
     /// Multiplies a vector with a scalar, and assigns the result back into the vector
-    fn mul_assign(&mut self, other: f64) {
+    fn mul_assign(&mut self, other: T) {
         *self = *self * other;
     }
 }
 
-impl ops::Mul<Vector3> for f64 {
-
-    /// Multiplies a scalar with a vector
-    type Output = Vector3;
-
-    fn mul(self, rhs: Vector3) -> Vector3 {
-        Vector3 {
-            x: self * rhs.x,
-            y: self * rhs.y,
-            z: self * rhs.z,
-        }
-    }
-}
-
-impl ops::Div<f64> for Vector3 {
+impl<T: Scalar> ops::Div<T> for Vector3<T> {
     type Output = Self;
 
     /// Divides a vector by a scalar
-    fn div(self, other: f64) -> Self {
+    fn div(self, other: T) -> Self {
         Vector3 {
             x: self.x / other,
             y: self.y / other,
@@ -213,34 +336,21 @@ impl ops::Div<f64> for Vector3 {
     }
 }
 
-impl ops::DivAssign<f64> for Vector3 {
+impl<T: Scalar> ops::DivAssign<T> for Vector3<T> {
     /// Divides a vector by a scalar, and assigns the result back into the vector
-    fn div_assign(&mut self, other: f64) {
+    fn div_assign(&mut self, other: T) {
         *self = *self / other;
     }
 }
 
-impl ops::Div<Vector3> for f64 {
-    type Output = Vector3;
-
-    /// Divides a scalar by a vector
-    fn div(self, other: Vector3) -> Vector3 {
-        Vector3 {
-            x: self / other.x,
-            y: self / other.y,
-            z: self / other.z,
-        }
-    }
-}
-
-impl Vector for Vector3 {
-    type Scalar = f64;
+impl<T: Scalar> Vector for Vector3<T> {
+    type Scalar = T;
 
     fn zero() -> Self {
         Vector3 {
-            x: 0.0,
-            y: 0.0,
-            z: 0.0,
+            x: T::zero(),
+            y: T::zero(),
+            z: T::zero(),
         }
     }
 
@@ -248,8 +358,8 @@ impl Vector for Vector3 {
         self.sqr_magnitude().sqrt()
     }
 
-    fn distance(&self, other: &Self) -> f64 {
-        self.distance(other)
+    fn distance(&self, other: &Self) -> T {
+        Vector3::distance(self, other)
     }
 
     fn normalized(self) -> Self {
@@ -262,13 +372,17 @@ impl Vector for Vector3 {
     }
 
     fn sqr_magnitude(&self) -> Self::Scalar {
-        (self.x.powi(2) + self.y.powi(2) + self.z.powi(2))
+        self.x.powi(2) + self.y.powi(2) + self.z.powi(2)
     }
 
-    fn angle(&self, other: &Self) -> Self::Scalar {
+    fn angle(&self, other: &Self) -> Rad<Self::Scalar> {
         let dot = self.dot(other);
         let mag = self.magnitude() * other.magnitude();
-        (dot / mag).acos()
+        Rad((dot / mag).acos())
+    }
+
+    fn project(self, other: Self) -> Self {
+        other.scale_by(self.dot(&other) / other.sqr_magnitude())
     }
 
     fn clamp_magnitude(self, max_len: Self::Scalar) -> Self {
@@ -279,8 +393,18 @@ impl Vector for Vector3 {
         }
     }
 
+    /// The dot product of two vectors, summed over all three components.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let a = Vector3::new(1.0, 2.0, 3.0);
+    /// let b = Vector3::new(4.0, 5.0, 6.0);
+    ///
+    /// assert_eq!(a.dot(&b), 32.0);
+    /// ```
     fn dot(&self, other: &Self) -> Self::Scalar {
-        self.x * other.x + self.y * other.y
+        self.x * other.x + self.y * other.y + self.z * other.z
     }
 
     /// Scales one vector by another by multiplying their components
@@ -293,9 +417,9 @@ impl Vector for Vector3 {
     }
 
     fn lerp(self, other: Self, t: Self::Scalar) -> Self {
-        if t <= 0.0 {
+        if t <= T::zero() {
             self
-        } else if t >= 1.0 {
+        } else if t >= T::one() {
             other
         } else {
             self.lerp_unclamped(other, t)
@@ -303,7 +427,7 @@ impl Vector for Vector3 {
     }
 
     fn lerp_unclamped(self, other: Self, t: Self::Scalar) -> Self {
-        (1.0 - t) * self + t * other
+        self.scale_by(T::one() - t) + other.scale_by(t)
     }
 
     fn move_towards(self, other: Self, max_distance_delta: Self::Scalar) -> Self {
@@ -312,35 +436,115 @@ impl Vector for Vector3 {
         self.lerp_unclamped(other, fraction)
     }
 
-    // fn move_towards(self, other: Self, max_distance_delta: Self::Scalar) -> Self {
-    //     unimplemented!("Unsure how this is supposed to be implemented");
-    // }
-
     /// Reflects the vector along the `normal` vector.
-    /// 
+    ///
     /// Example:
-    /// 
+    ///
     /// ```
     /// # use vect::prelude::*;
     /// let a = Vector3::new(1.0, 2.0, 0.0);
     /// let n = Vector3::up();
     /// let r = a.reflect(n);
-    /// 
+    ///
     /// assert_eq!(r, Vector3::new(1.0, -2.0, 0.0));
     /// ```
     fn reflect(self, normal: Self) -> Self {
-        -2.0 * self.dot(&normal) * normal + self
+        let two = T::one() + T::one();
+        self - normal.scale_by(two * self.dot(&normal))
     }
 }
 
-impl From<Vector2> for Vector3 {
+impl<T: Scalar> From<Vector2<T>> for Vector3<T> {
 
     /// Creates a `Vector3` from a `Vector2`, adding a z component of 0
-    fn from(vector: Vector2) -> Vector3 {
+    fn from(vector: Vector2<T>) -> Vector3<T> {
         Vector3 {
             x: vector.x,
             y: vector.y,
-            z: 0.0,
+            z: T::zero(),
+        }
+    }
+}
+
+impl<T: Scalar> ops::Index<usize> for Vector3<T> {
+    type Output = T;
+
+    /// Indexes into the vector's components, `0` for `x`, `1` for `y`, `2` for `z`.
+    ///
+    /// Panics if `index` is out of range.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    ///
+    /// assert_eq!(v[0], 1.0);
+    /// assert_eq!(v[1], 2.0);
+    /// assert_eq!(v[2], 3.0);
+    /// ```
+    ///
+    /// Out-of-range access panics:
+    /// ```should_panic
+    /// # use vect::prelude::*;
+    /// let v = Vector3::new(1.0, 2.0, 3.0);
+    /// let _ = v[3];
+    /// ```
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("index out of range for Vector3: {}", index),
+        }
+    }
+}
+
+impl<T: Scalar> ops::IndexMut<usize> for Vector3<T> {
+    /// Mutably indexes into the vector's components, `0` for `x`, `1` for `y`, `2` for `z`.
+    ///
+    /// Panics if `index` is out of range.
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("index out of range for Vector3: {}", index),
         }
     }
-}
\ No newline at end of file
+}
+
+impl<T: Scalar> iter::Sum for Vector3<T> {
+    /// Sums an iterator of vectors, e.g. for computing a centroid with `sum() / n`.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let points = [
+    ///     Vector3::new(1.0, 0.0, 0.0),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    ///     Vector3::new(0.0, 0.0, 1.0),
+    /// ];
+    /// let centroid = points.iter().copied().sum::<Vector3<f64>>() / points.len() as f64;
+    ///
+    /// assert_eq!(centroid, Vector3::splat(1.0 / 3.0));
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Vector3::zero(), |a, b| a + b)
+    }
+}
+
+impl<T: Scalar> iter::Product for Vector3<T> {
+    /// Multiplies an iterator of vectors component-wise.
+    ///
+    /// Example:
+    /// ```
+    /// # use vect::prelude::*;
+    /// let factors = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)];
+    /// let product = factors.iter().copied().product::<Vector3<f64>>();
+    ///
+    /// assert_eq!(product, Vector3::new(4.0, 10.0, 18.0));
+    /// ```
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Vector3::splat(T::one()), |a, b| a.scale(b))
+    }
+}
@@ -0,0 +1,270 @@
+//! Standard implementation of a 4D Vector.
+//!
+//! Generic over its scalar component type `T` (anything implementing [`Scalar`]),
+//! mirroring [`Vector3`]. Mostly useful for homogeneous coordinates once matrix/transform
+//! support is in the picture.
+
+use std::ops;
+
+use super::prelude::{Rad, Scalar, Vector, Vector2, Vector3};
+
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector4<T: Scalar = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+impl<T: Scalar> Vector4<T> {
+
+    /// Creates a new `Vector4`
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Vector4 { x, y, z, w }
+    }
+
+    /// Returns the distance between two vectors.
+    ///
+    /// `a.distance(b)` is the same as `(a - b).magnitude()`.
+    pub fn distance(&self, other: &Self) -> T {
+        (*self - *other).magnitude()
+    }
+
+    /// Multiplies every component of the vector by `s`.
+    ///
+    /// This is the scalar-on-the-left direction of `Vector4 * T`; it exists as an
+    /// inherent method because a generic `impl<T: Scalar> Mul<Vector4<T>> for T` would
+    /// violate the orphan rules (`T` isn't a type this crate owns).
+    pub fn scale_by(self, s: T) -> Self {
+        self * s
+    }
+}
+
+impl<T: Scalar> ops::Add for Vector4<T> {
+    type Output = Self;
+
+    /// Adds two vectors together
+    fn add(self, other: Self) -> Self {
+        Vector4 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+            w: self.w + other.w,
+        }
+    }
+}
+
+impl<T: Scalar> ops::AddAssign for Vector4<T> {
+
+    /// Adds two vectors together, and assigns the result into the first vector
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Scalar> ops::Sub for Vector4<T> {
+    type Output = Self;
+
+    /// Subtracts two vectors from each other
+    fn sub(self, other: Self) -> Self {
+        Vector4 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+            w: self.w - other.w,
+        }
+    }
+}
+
+impl<T: Scalar> ops::SubAssign for Vector4<T> {
+
+    /// Subtracts two vectors from each other, and assigns the result into the first vector
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Scalar> ops::Mul<T> for Vector4<T> {
+    type Output = Self;
+
+    /// Multiplies a vector with a scalar
+    fn mul(self, rhs: T) -> Self {
+        Vector4 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+            w: self.w * rhs,
+        }
+    }
+}
+
+impl<T: Scalar> ops::MulAssign<T> for Vector4<T> {
+
+    /// Multiplies a vector with a scalar, and assigns the result back into the vector
+    fn mul_assign(&mut self, other: T) {
+        *self = *self * other;
+    }
+}
+
+impl<T: Scalar> ops::Div<T> for Vector4<T> {
+    type Output = Self;
+
+    /// Divides a vector by a scalar
+    fn div(self, other: T) -> Self {
+        Vector4 {
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+            w: self.w / other,
+        }
+    }
+}
+
+impl<T: Scalar> ops::DivAssign<T> for Vector4<T> {
+    /// Divides a vector by a scalar, and assigns the result back into the vector
+    fn div_assign(&mut self, other: T) {
+        *self = *self / other;
+    }
+}
+
+impl<T: Scalar> Vector for Vector4<T> {
+    type Scalar = T;
+
+    fn zero() -> Self {
+        Vector4 {
+            x: T::zero(),
+            y: T::zero(),
+            z: T::zero(),
+            w: T::zero(),
+        }
+    }
+
+    fn magnitude(&self) -> Self::Scalar {
+        self.sqr_magnitude().sqrt()
+    }
+
+    fn distance(&self, other: &Self) -> T {
+        Vector4::distance(self, other)
+    }
+
+    fn normalized(self) -> Self {
+        let mag = self.magnitude();
+        self / mag
+    }
+
+    fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    fn sqr_magnitude(&self) -> Self::Scalar {
+        self.x.powi(2) + self.y.powi(2) + self.z.powi(2) + self.w.powi(2)
+    }
+
+    fn angle(&self, other: &Self) -> Rad<Self::Scalar> {
+        let dot = self.dot(other);
+        let mag = self.magnitude() * other.magnitude();
+        Rad((dot / mag).acos())
+    }
+
+    fn project(self, other: Self) -> Self {
+        other.scale_by(self.dot(&other) / other.sqr_magnitude())
+    }
+
+    fn clamp_magnitude(self, max_len: Self::Scalar) -> Self {
+        if self.magnitude() > max_len {
+            self.normalized().scale_by(max_len)
+        } else {
+            self
+        }
+    }
+
+    fn dot(&self, other: &Self) -> Self::Scalar {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Scales one vector by another by multiplying their components
+    fn scale(self, other: Self) -> Self {
+        Vector4 {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+            w: self.w * other.w,
+        }
+    }
+
+    fn lerp(self, other: Self, t: Self::Scalar) -> Self {
+        if t <= T::zero() {
+            self
+        } else if t >= T::one() {
+            other
+        } else {
+            self.lerp_unclamped(other, t)
+        }
+    }
+
+    fn lerp_unclamped(self, other: Self, t: Self::Scalar) -> Self {
+        self.scale_by(T::one() - t) + other.scale_by(t)
+    }
+
+    fn move_towards(self, other: Self, max_distance_delta: Self::Scalar) -> Self {
+        let distance = self.distance(&other);
+        let fraction = max_distance_delta / distance;
+        self.lerp_unclamped(other, fraction)
+    }
+
+    /// Reflects the vector along the `normal` vector.
+    fn reflect(self, normal: Self) -> Self {
+        let two = T::one() + T::one();
+        self - normal.scale_by(two * self.dot(&normal))
+    }
+}
+
+impl<T: Scalar> From<Vector3<T>> for Vector4<T> {
+
+    /// Creates a `Vector4` from a `Vector3`, adding a w component of 0
+    fn from(vector: Vector3<T>) -> Vector4<T> {
+        Vector4 {
+            x: vector.x,
+            y: vector.y,
+            z: vector.z,
+            w: T::zero(),
+        }
+    }
+}
+
+impl<T: Scalar> From<Vector2<T>> for Vector4<T> {
+
+    /// Creates a `Vector4` from a `Vector2`, adding z and w components of 0
+    fn from(vector: Vector2<T>) -> Vector4<T> {
+        Vector4 {
+            x: vector.x,
+            y: vector.y,
+            z: T::zero(),
+            w: T::zero(),
+        }
+    }
+}
+
+impl<T: Scalar> From<Vector4<T>> for Vector3<T> {
+
+    /// Creates a `Vector3` from a `Vector4`, discarding the w component
+    fn from(vector: Vector4<T>) -> Vector3<T> {
+        Vector3 {
+            x: vector.x,
+            y: vector.y,
+            z: vector.z,
+        }
+    }
+}
+
+impl<T: Scalar> From<Vector4<T>> for Vector2<T> {
+
+    /// Creates a `Vector2` from a `Vector4`, discarding the z and w components
+    fn from(vector: Vector4<T>) -> Vector2<T> {
+        Vector2 {
+            x: vector.x,
+            y: vector.y,
+        }
+    }
+}